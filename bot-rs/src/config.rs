@@ -0,0 +1,33 @@
+use anyhow::Context;
+use std::env;
+
+const DEFAULT_GITEA_BASE_URL: &str = "https://gitea.com/api/v1";
+const DEFAULT_MODEL: &str = "anthropic/claude-3.5-sonnet";
+
+/// Configuration loaded from the environment (and `.env`, via `dotenvy`).
+#[derive(Debug)]
+pub struct Config {
+    pub gitea_token: String,
+    pub gitea_base_url: String,
+    pub openrouter_token: String,
+    pub model: String,
+}
+
+impl Config {
+    /// Reads `GITEA_TOKEN`, `GITEA_BASE_URL` (optional), `OPENROUTER_TOKEN` and
+    /// `MODEL` (optional) from the environment.
+    pub fn load() -> anyhow::Result<Self> {
+        let gitea_token = env::var("GITEA_TOKEN").context("Missing GITEA_TOKEN environment variable")?;
+        let gitea_base_url = env::var("GITEA_BASE_URL").unwrap_or_else(|_| DEFAULT_GITEA_BASE_URL.to_string());
+        let openrouter_token =
+            env::var("OPENROUTER_TOKEN").context("Missing OPENROUTER_TOKEN environment variable")?;
+        let model = env::var("MODEL").unwrap_or_else(|_| DEFAULT_MODEL.to_string());
+
+        Ok(Config {
+            gitea_token,
+            gitea_base_url,
+            openrouter_token,
+            model,
+        })
+    }
+}
@@ -0,0 +1,234 @@
+/// A single `@@ -a,b +c,d @@` hunk from a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub old_start: u64,
+    pub old_lines: u64,
+    pub new_start: u64,
+    pub new_lines: u64,
+    pub lines: Vec<String>,
+}
+
+/// The changes made to a single file within a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    pub old_path: String,
+    pub new_path: String,
+    pub hunks: Vec<Hunk>,
+}
+
+impl FileDiff {
+    /// Returns `(start, end)` new-file line ranges covered by this file's hunks.
+    pub fn changed_ranges(&self) -> Vec<(u64, u64)> {
+        self.hunks
+            .iter()
+            .map(|hunk| (hunk.new_start, hunk.new_start + hunk.new_lines.saturating_sub(1)))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for FileDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "--- {}", self.old_path)?;
+        writeln!(f, "+++ {}", self.new_path)?;
+        for hunk in &self.hunks {
+            writeln!(
+                f,
+                "@@ -{},{} +{},{} @@",
+                hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines
+            )?;
+            for line in &hunk.lines {
+                writeln!(f, "{}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the `@@ -a,b +c,d @@` header into `(old_start, old_lines, new_start, new_lines)`.
+fn parse_hunk_header(line: &str) -> anyhow::Result<(u64, u64, u64, u64)> {
+    let body = line
+        .strip_prefix("@@ ")
+        .and_then(|s| s.split(" @@").next())
+        .ok_or_else(|| anyhow::anyhow!("Malformed hunk header: {}", line))?;
+
+    let mut parts = body.split_whitespace();
+    let old = parts
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Malformed hunk header: {}", line))?;
+    let new = parts
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Malformed hunk header: {}", line))?;
+
+    let parse_range = |range: &str| -> anyhow::Result<(u64, u64)> {
+        let range = range.trim_start_matches(['+', '-']);
+        match range.split_once(',') {
+            Some((start, count)) => Ok((start.parse()?, count.parse()?)),
+            None => Ok((range.parse()?, 1)),
+        }
+    };
+
+    let (old_start, old_lines) = parse_range(old)?;
+    let (new_start, new_lines) = parse_range(new)?;
+
+    Ok((old_start, old_lines, new_start, new_lines))
+}
+
+/// Parses a unified diff (as produced by `git diff` or Gitea's `.diff` endpoint)
+/// into one `FileDiff` per file.
+pub fn parse_unified_diff(diff: &str) -> anyhow::Result<Vec<FileDiff>> {
+    let mut files = Vec::new();
+    let mut current: Option<FileDiff> = None;
+    let mut current_hunk: Option<Hunk> = None;
+
+    let empty_file_diff = || FileDiff {
+        old_path: String::new(),
+        new_path: String::new(),
+        hunks: Vec::new(),
+    };
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git") {
+            if let Some(hunk) = current_hunk.take() {
+                current.get_or_insert_with(empty_file_diff).hunks.push(hunk);
+            }
+            if let Some(file) = current.take() {
+                files.push(file);
+            }
+            current = Some(empty_file_diff());
+        } else if let Some(path) = line.strip_prefix("--- ") {
+            current.get_or_insert_with(empty_file_diff).old_path = strip_diff_prefix(path);
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            current.get_or_insert_with(empty_file_diff).new_path = strip_diff_prefix(path);
+        } else if line.starts_with("@@ ") {
+            if let Some(hunk) = current_hunk.take() {
+                current.get_or_insert_with(empty_file_diff).hunks.push(hunk);
+            }
+            let (old_start, old_lines, new_start, new_lines) = parse_hunk_header(line)?;
+            current_hunk = Some(Hunk {
+                old_start,
+                old_lines,
+                new_start,
+                new_lines,
+                lines: Vec::new(),
+            });
+        } else if let Some(hunk) = current_hunk.as_mut() {
+            hunk.lines.push(line.to_string());
+        }
+    }
+
+    if let Some(hunk) = current_hunk.take() {
+        current.get_or_insert_with(empty_file_diff).hunks.push(hunk);
+    }
+    if let Some(file) = current.take() {
+        files.push(file);
+    }
+
+    Ok(files)
+}
+
+/// Strips the `a/` or `b/` prefix that `git diff` paths carry; `/dev/null` is
+/// returned unchanged.
+fn strip_diff_prefix(path: &str) -> String {
+    if path == "/dev/null" {
+        return path.to_string();
+    }
+    path.strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_file_single_hunk() {
+        let diff = [
+            "diff --git a/foo.rs b/foo.rs",
+            "--- a/foo.rs",
+            "+++ b/foo.rs",
+            "@@ -1,2 +1,3 @@",
+            " context",
+            "-removed",
+            "+added",
+            "",
+        ]
+        .join("\n");
+
+        let files = parse_unified_diff(&diff).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].old_path, "foo.rs");
+        assert_eq!(files[0].new_path, "foo.rs");
+        assert_eq!(files[0].hunks.len(), 1);
+        assert_eq!(files[0].hunks[0].lines, vec![" context", "-removed", "+added"]);
+    }
+
+    #[test]
+    fn parses_multiple_files_with_multiple_hunks() {
+        let diff = "diff --git a/a.rs b/a.rs\n\
+                     --- a/a.rs\n\
+                     +++ b/a.rs\n\
+                     @@ -1,1 +1,1 @@\n\
+                     -old a\n\
+                     +new a\n\
+                     @@ -10,1 +10,1 @@\n\
+                     -old a2\n\
+                     +new a2\n\
+                     diff --git a/b.rs b/b.rs\n\
+                     --- a/b.rs\n\
+                     +++ b/b.rs\n\
+                     @@ -1,1 +1,1 @@\n\
+                     -old b\n\
+                     +new b\n";
+
+        let files = parse_unified_diff(diff).unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].new_path, "a.rs");
+        assert_eq!(files[0].hunks.len(), 2);
+        assert_eq!(files[1].new_path, "b.rs");
+        assert_eq!(files[1].hunks.len(), 1);
+    }
+
+    #[test]
+    fn does_not_panic_on_hunk_without_diff_git_header() {
+        let diff = "@@ -1,1 +1,1 @@\n-foo\n+bar\n";
+
+        let files = parse_unified_diff(diff).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].hunks.len(), 1);
+        assert_eq!(files[0].old_path, "");
+    }
+
+    #[test]
+    fn changed_ranges_covers_each_hunks_new_file_lines() {
+        let diff = [
+            "diff --git a/a.rs b/a.rs",
+            "--- a/a.rs",
+            "+++ b/a.rs",
+            "@@ -1,1 +1,3 @@",
+            "+one",
+            "+two",
+            " three",
+            "@@ -10,1 +12,1 @@",
+            "-old",
+            "+new",
+            "",
+        ]
+        .join("\n");
+
+        let files = parse_unified_diff(&diff).unwrap();
+        assert_eq!(files[0].changed_ranges(), vec![(1, 3), (12, 12)]);
+    }
+
+    #[test]
+    fn parses_binary_file_diff_with_no_hunks() {
+        let diff = "diff --git a/image.png b/image.png\n\
+                     index 1234567..89abcde 100644\n\
+                     Binary files a/image.png and b/image.png differ\n";
+
+        let files = parse_unified_diff(diff).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].hunks.is_empty());
+    }
+}
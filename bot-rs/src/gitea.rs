@@ -0,0 +1,131 @@
+use anyhow::Context;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::Method;
+
+/// Pulls `(owner, repo, index)` out of a Gitea PR web URL, e.g.
+/// `https://git.example/owner/repo/pulls/42`.
+pub fn parse_pr_url(url: &str) -> anyhow::Result<(String, String, u64)> {
+    let parsed = reqwest::Url::parse(url).with_context(|| format!("Invalid URL: {}", url))?;
+
+    let segments: Vec<&str> = parsed
+        .path_segments()
+        .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+        .unwrap_or_default();
+
+    match segments.as_slice() {
+        [owner, repo, "pulls", index] => {
+            let index = index
+                .parse::<u64>()
+                .with_context(|| format!("PR index '{}' in URL is not a number", index))?;
+            Ok((owner.to_string(), repo.to_string(), index))
+        }
+        _ => anyhow::bail!(
+            "Expected a Gitea PR URL like 'https://host/owner/repo/pulls/42', got: {}",
+            url
+        ),
+    }
+}
+
+/// Thin wrapper around a `reqwest::Client` authenticated against a Gitea instance.
+pub struct GiteaClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl GiteaClient {
+    /// Builds a client authenticated with `token`, targeting `base_url`.
+    pub fn new(token: &str, base_url: &str) -> anyhow::Result<Self> {
+        let base_url = base_url.to_string();
+
+        let auth_header = HeaderValue::from_str(&format!("token {}", token))
+            .context("Failed to format token into header value")?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", auth_header);
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()?;
+
+        Ok(GiteaClient { client, base_url })
+    }
+
+    /// Builds a `RequestBuilder` for `path`, prepending `base_url` unless `path`
+    /// already starts with `https://`.
+    fn prepare(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+        let url = if path.starts_with("https://") {
+            path.to_string()
+        } else {
+            format!("{}{}", self.base_url.trim_end_matches('/'), path)
+        };
+
+        self.client.request(method, url)
+    }
+
+    /// Fetches the unified diff for a pull request via Gitea's `.diff` endpoint.
+    pub async fn get_pr_diff(&self, owner: &str, repo: &str, index: u64) -> anyhow::Result<String> {
+        let path = format!("/repos/{}/{}/pulls/{}.diff", owner, repo, index);
+
+        let body = self
+            .prepare(Method::GET, &path)
+            .header("Accept", "text/plain")
+            .send()
+            .await?
+            .error_for_status()
+            .context("Failed to fetch PR diff")?
+            .text()
+            .await?;
+
+        Ok(body)
+    }
+
+    /// Posts `body` as a comment on the given pull request.
+    pub async fn post_review_comment(&self, owner: &str, repo: &str, index: u64, body: &str) -> anyhow::Result<()> {
+        let path = format!("/repos/{}/{}/issues/{}/comments", owner, repo, index);
+
+        self.prepare(Method::POST, &path)
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await?
+            .error_for_status()
+            .context("Failed to post review comment")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pr_url_extracts_owner_repo_index() {
+        let (owner, repo, index) = parse_pr_url("https://git.example/owner/repo/pulls/42").unwrap();
+        assert_eq!(owner, "owner");
+        assert_eq!(repo, "repo");
+        assert_eq!(index, 42);
+    }
+
+    #[test]
+    fn parse_pr_url_ignores_trailing_slash() {
+        let (owner, repo, index) = parse_pr_url("https://git.example/owner/repo/pulls/42/").unwrap();
+        assert_eq!(owner, "owner");
+        assert_eq!(repo, "repo");
+        assert_eq!(index, 42);
+    }
+
+    #[test]
+    fn parse_pr_url_rejects_non_pr_url() {
+        assert!(parse_pr_url("https://git.example/owner/repo").is_err());
+    }
+
+    #[test]
+    fn parse_pr_url_rejects_non_numeric_index() {
+        assert!(parse_pr_url("https://git.example/owner/repo/pulls/abc").is_err());
+    }
+
+    #[test]
+    fn parse_pr_url_rejects_unparseable_url() {
+        assert!(parse_pr_url("not a url").is_err());
+    }
+}
@@ -1,9 +1,14 @@
-use reqwest::{header::{HeaderMap, HeaderValue}};
-use anyhow::{Context};
 use clap::Parser;
-use std::env;
 use tokio;
 
+mod config;
+mod diff;
+mod gitea;
+mod review;
+
+use config::Config;
+use gitea::GiteaClient;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
@@ -12,59 +17,70 @@ struct Args {
 
     #[arg(short, long, value_name = "ISSUE", help = "Output help")]
     pr: Option<String>,
-}
-
-//#[derive(Debug)]
-//struct Config {
-//    gitea_token: String,
-//    openrouter_token: String,
-//
-//}
-//
-//impl Config {
-//    fn from_env() -> Result<Self, Box<dyn Error>> {
-//        let gitea_token = env::var("GITEA_TOKEN")?;
-//        let openrouter_token = env::var("OPENROUTER_TOKEN")?;
-//
-//        Ok(Config { gitea_token, openrouter_token })
-//    }
-//}
-
-async fn get_diff(url: &str, token: &str) -> anyhow::Result<String> {
-    let auth_header = HeaderValue::from_str(&format!("token {}", token)).context("Failed to format token into header value")?;
 
-    let mut headers = HeaderMap::new();
-    headers.insert("Authorization", auth_header);
+    #[arg(long, value_name = "MODEL", help = "OpenRouter model to use for the review")]
+    model: Option<String>,
 
-    let client = reqwest::Client::builder().default_headers(headers).build()?;
-
-    let response = client.get(url).send().await?.error_for_status();
-    let _response2 = client.post(url).body("Text").send().await?.error_for_status();
-    let body = response?.text().await?;
-    Ok(body)
+    #[arg(
+        long,
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+        help = "Print the review instead of posting it; pass --dry-run false to submit"
+    )]
+    dry_run: bool,
 }
 
 #[tokio::main]
 async fn main() {
-    let gitea_token = match env::var("GITEA_TOKEN") {
-        Ok(val) => val,
-        Err(_) => {
-            eprintln!("Missing GITEA_TOKEN environment variable");
+    dotenvy::dotenv().ok();
+
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{:#}", e);
             std::process::exit(1);
         }
     };
-    let _openrouter_token = match env::var("OPENROUTER_TOKEN") {
-        Ok(val) => val,
-        Err(_) => {
-            eprintln!("Missing OPENROUTER_TOKEN environment variable");
+
+    let args = Args::parse();
+    let model = args.model.unwrap_or_else(|| config.model.clone());
+
+    let client = match GiteaClient::new(&config.gitea_token, &config.gitea_base_url) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
             std::process::exit(1);
         }
     };
 
-    let args = Args::parse();
+    let result = async {
+        let (owner, repo, index) = gitea::parse_pr_url(&args.url)?;
+        let raw_diff = client.get_pr_diff(&owner, &repo, index).await?;
+        let files = diff::parse_unified_diff(&raw_diff)?;
+
+        let mut reviews = Vec::new();
+        for file in &files {
+            let review = review::review_diff(&file.to_string(), &config.openrouter_token, &model).await?;
+            let ranges: Vec<String> = file
+                .changed_ranges()
+                .into_iter()
+                .map(|(start, end)| format!("{}-{}", start, end))
+                .collect();
+            reviews.push(format!("## {} (lines {})\n{}", file.new_path, ranges.join(", "), review));
+        }
+        let review = reviews.join("\n\n");
+
+        if args.dry_run {
+            println!("{}", review);
+        } else {
+            client.post_review_comment(&owner, &repo, index, &review).await?;
+        }
+
+        Ok::<(), anyhow::Error>(())
+    }
+    .await;
 
-    match get_diff(&args.url, &gitea_token).await {
-        Ok(body) => println!("Function successful got \n{}", body),
-        Err(e) => println!("Error {}", e),
+    if let Err(e) = result {
+        println!("Error {}", e);
     }
 }
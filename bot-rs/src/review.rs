@@ -0,0 +1,76 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+const OPENROUTER_URL: &str = "https://openrouter.ai/api/v1/chat/completions";
+
+const REVIEWER_PROMPT: &str = "You are an experienced software engineer reviewing a pull request. \
+Read the unified diff and give concise, actionable feedback: point out bugs, risky changes, \
+missing tests, and style issues. Do not restate the diff back to the author.";
+
+#[derive(Debug, Serialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatResponseMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponseMessage {
+    content: String,
+}
+
+/// Sends `diff` to OpenRouter's chat completions endpoint and returns the model's review.
+pub async fn review_diff(diff: &str, token: &str, model: &str) -> anyhow::Result<String> {
+    let client = reqwest::Client::new();
+
+    let request = ChatRequest {
+        model: model.to_string(),
+        messages: vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: REVIEWER_PROMPT.to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: diff.to_string(),
+            },
+        ],
+    };
+
+    let response = client
+        .post(OPENROUTER_URL)
+        .bearer_auth(token)
+        .json(&request)
+        .send()
+        .await?
+        .error_for_status()
+        .context("OpenRouter request failed")?
+        .json::<ChatResponse>()
+        .await
+        .context("Failed to parse OpenRouter response")?;
+
+    let review = response
+        .choices
+        .into_iter()
+        .next()
+        .context("OpenRouter response contained no choices")?
+        .message
+        .content;
+
+    Ok(review)
+}